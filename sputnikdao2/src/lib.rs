@@ -0,0 +1,3 @@
+pub mod policy;
+pub mod proposals;
+pub mod types;
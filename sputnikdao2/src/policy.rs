@@ -45,6 +45,14 @@ impl RoleKind {
             _ => None,
         }
     }
+
+    /// Returns the explicit list of members of this role, or None if not supported role kind.
+    pub fn get_role_members(&self) -> Option<&Vec<AccountId>> {
+        match self {
+            RoleKind::Group(accounts) => Some(accounts),
+            _ => None,
+        }
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
@@ -88,10 +96,57 @@ impl WeightOrRatio {
 pub enum WeightKind {
     /// Using token amounts and total supply.
     TokenWeight,
+    /// Using the integer square root of token amounts and of total supply, to dampen
+    /// the influence of large holders relative to plain `TokenWeight`.
+    QuadraticTokenWeight,
     /// Weight of the group role. Roles that don't have scoped group are not supported.
     RoleWeight(String),
 }
 
+/// Integer square root via Newton's method, used to compute quadratic voting weights.
+pub fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+    let mut x = n;
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// A voter's allocation of their voting weight across a proposal's named options.
+/// The `weight_percentage` values across all choices cast in a single vote must sum to 100.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VoteChoice {
+    /// Index into the proposal's list of options.
+    pub option_index: u8,
+    /// Share of the voter's weight given to this option, in integer percent.
+    pub weight_percentage: u8,
+}
+
+impl VoteChoice {
+    /// Checks that every choice references a valid option and that the percentages
+    /// across all choices sum to exactly 100.
+    pub fn validate(choices: &[VoteChoice], num_options: u8) -> bool {
+        if choices.iter().any(|choice| choice.option_index >= num_options) {
+            return false;
+        }
+        let total: u16 = choices
+            .iter()
+            .map(|choice| choice.weight_percentage as u16)
+            .sum();
+        total == 100
+    }
+}
+
 /// Defines configuration of the vote.
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
 #[serde(crate = "near_sdk::serde")]
@@ -100,6 +155,14 @@ pub struct VotePolicy {
     pub weight_kind: WeightKind,
     /// How many votes to pass this vote.
     pub threshold: WeightOrRatio,
+    /// Prime member whose recorded vote is imputed to role members who abstained,
+    /// once the proposal's voting period has elapsed without crossing the threshold.
+    /// Must be a member of the role referenced by `weight_kind`'s `RoleWeight`.
+    pub prime: Option<AccountId>,
+    /// Minimum total participation (sum of votes across Approve/Reject/Remove) required
+    /// before any outcome can be finalized, regardless of how lopsided the votes are.
+    /// Defaults to zero weight, which preserves the previous no-quorum behavior.
+    pub quorum: WeightOrRatio,
 }
 
 impl Default for VotePolicy {
@@ -107,6 +170,8 @@ impl Default for VotePolicy {
         VotePolicy {
             weight_kind: WeightKind::RoleWeight("council".to_string()),
             threshold: WeightOrRatio::Ratio(1, 2),
+            prime: None,
+            quorum: WeightOrRatio::Weight(U128(0)),
         }
     }
 }
@@ -125,6 +190,14 @@ pub struct Policy {
     pub bounty_bond: U128,
     /// Period in which giving up on bounty is not punished.
     pub bounty_forgiveness_period: WrappedDuration,
+    /// Parent DAO this policy is a sub-DAO of, if any. Roles resolved from the parent's
+    /// (and its own ancestors') policy via `resolve_parent_roles` are unioned into this
+    /// policy's own roles, with a locally defined role of the same name taking
+    /// precedence over a parent's.
+    pub parent_dao: Option<AccountId>,
+    /// Account that holds `*:*` regardless of role membership, typically the parent DAO
+    /// itself, to support an emergency override of a sub-DAO's decisions.
+    pub sudo: Option<AccountId>,
 }
 
 impl Default for Policy {
@@ -152,30 +225,121 @@ impl Default for Policy {
             vote_policy: HashMap::default(),
             bounty_bond: U128(10u128.pow(24)),
             bounty_forgiveness_period: WrappedDuration::from(1_000_000_000 * 60 * 60 * 24),
+            parent_dao: None,
+            sudo: None,
         }
     }
 }
 
 impl Policy {
-    /// Returns set of permissions for given user across all the roles it's member of.
-    fn get_user_permissions(&self, user: UserInfo) -> HashSet<String> {
+    /// Checks invariants that can't be expressed in the type system, meant to be called
+    /// whenever a new `Policy` is persisted (e.g. on DAO creation or `ChangePolicy`),
+    /// rather than on every read. Panics if a `VotePolicy`'s `prime` is set but isn't a
+    /// member of the role its `weight_kind` resolves to.
+    pub fn validate(&self) {
+        let vote_policies = std::iter::once(&self.default_vote_policy)
+            .chain(self.vote_policy.values());
+        for vote_policy in vote_policies {
+            let prime = match &vote_policy.prime {
+                Some(prime) => prime,
+                None => continue,
+            };
+            if let WeightKind::RoleWeight(role) = &vote_policy.weight_kind {
+                let role = self.internal_get_role(role).expect("ERR_MISSING_ROLE");
+                assert!(
+                    role.kind.match_user(&UserInfo {
+                        account_id: prime.clone(),
+                        amount: None,
+                    }),
+                    "ERR_PRIME_NOT_MEMBER"
+                );
+            }
+        }
+    }
+
+    /// Walks the `parent_dao` chain, resolving and flattening each ancestor's roles,
+    /// with a nearer ancestor's role taking precedence over a same-named role from one
+    /// further up the chain. `fetch_policy` stands in for the cross-contract view call
+    /// that would fetch an ancestor's policy in a deployed contract; the caller is
+    /// responsible for caching its results the same way it would cache any other
+    /// cross-contract view response. Panics with `ERR_PARENT_DAO_CYCLE` if the same
+    /// account is visited twice while walking the chain.
+    pub fn resolve_parent_roles(
+        &self,
+        fetch_policy: impl Fn(&AccountId) -> Option<Policy>,
+    ) -> Vec<RolePermission> {
+        let mut roles = Vec::new();
+        let mut seen_names: HashSet<String> =
+            self.roles.iter().map(|role| role.name.clone()).collect();
+        let mut visited: HashSet<AccountId> = HashSet::new();
+        let mut current = self.parent_dao.clone();
+        while let Some(parent_account) = current {
+            assert!(
+                visited.insert(parent_account.clone()),
+                "ERR_PARENT_DAO_CYCLE"
+            );
+            let parent_policy = match fetch_policy(&parent_account) {
+                Some(policy) => policy,
+                None => break,
+            };
+            for role in parent_policy.roles.iter() {
+                if seen_names.insert(role.name.clone()) {
+                    roles.push(role.clone());
+                }
+            }
+            current = parent_policy.parent_dao.clone();
+        }
+        roles
+    }
+
+    /// Returns set of permissions for given user across all the roles it's member of,
+    /// plus any roles resolved from the parent DAO's policy that aren't shadowed by a
+    /// locally defined role of the same name. `parent_roles`, if any, must already be
+    /// resolved via `resolve_parent_roles` (or an equivalent caller-side cache of it) —
+    /// this method does no fetching of its own.
+    fn get_user_permissions(
+        &self,
+        user: UserInfo,
+        parent_roles: Option<&Vec<RolePermission>>,
+    ) -> HashSet<String> {
         let mut result = HashSet::default();
+        let local_names: HashSet<&String> = self.roles.iter().map(|role| &role.name).collect();
         for role in self.roles.iter() {
             if role.kind.match_user(&user) {
                 result = result.union(&role.permissions).cloned().collect();
             }
         }
+        if let Some(parent_roles) = parent_roles {
+            for role in parent_roles.iter() {
+                if !local_names.contains(&role.name) && role.kind.match_user(&user) {
+                    result = result.union(&role.permissions).cloned().collect();
+                }
+            }
+        }
         result
     }
 
-    /// Can given user execute given action on this proposal.
+    /// Can given user execute given action on this proposal. The `sudo` account, if set,
+    /// always can, regardless of role membership. `parent_roles`, when this policy has a
+    /// `parent_dao`, are the roles resolved from the parent's policy via
+    /// `resolve_parent_roles`, unioned in unless shadowed by a same-named local role.
+    ///
+    /// Matching is purely on the `<proposal_kind>:<action>` label, so an
+    /// `Action::VoteApproveNoExecute` is a distinct label from `Action::VoteApprove` here:
+    /// a role can be granted the former without the latter, letting a council vote a
+    /// proposal into `ProposalStatus::ApprovedPendingExecution` while a different, separately permissioned
+    /// role is the only one that can later invoke the action that actually executes it.
     pub fn can_execute_action(
         &self,
         user: UserInfo,
         proposal_kind: &ProposalKind,
         action: &Action,
+        parent_roles: Option<&Vec<RolePermission>>,
     ) -> bool {
-        let permissions = self.get_user_permissions(user);
+        if self.sudo.as_ref() == Some(&user.account_id) {
+            return true;
+        }
+        let permissions = self.get_user_permissions(user, parent_roles);
         permissions.contains(&format!(
             "{}:{}",
             proposal_kind.to_policy_label(),
@@ -193,11 +357,25 @@ impl Policy {
             .unwrap_or(&self.default_vote_policy)
             .weight_kind
         {
-            WeightKind::TokenWeight => true,
+            WeightKind::TokenWeight | WeightKind::QuadraticTokenWeight => true,
             _ => false,
         }
     }
 
+    /// Tallies quadratic voting weight: each voter's balance contributes its integer
+    /// square root instead of its raw amount. `balances` are the token balances of the
+    /// voters who voted in the given direction. The matching total weight is the sum of
+    /// square roots across the whole electorate, as computed by `Policy::quadratic_total_weight`.
+    pub fn quadratic_vote_weight(&self, balances: &[Balance]) -> Balance {
+        balances.iter().map(|balance| isqrt(*balance)).sum()
+    }
+
+    /// Computes the quadratic total weight of the electorate, i.e. the sum of the
+    /// integer square roots of every voter's balance.
+    pub fn quadratic_total_weight(&self, balances: &[Balance]) -> Balance {
+        self.quadratic_vote_weight(balances)
+    }
+
     fn internal_get_role(&self, name: &String) -> Option<RolePermission> {
         for role in self.roles.iter() {
             if role.name == *name {
@@ -207,9 +385,47 @@ impl Policy {
         None
     }
 
+    /// Tallies weighted multi-option votes and returns the index of the first option
+    /// whose accumulated weight crosses `threshold`, if any.
+    /// Each entry in `votes` is a voter's total weight paired with the `VoteChoice`s
+    /// that split that weight across the proposal's options. Choices are assumed to
+    /// already be valid: `Proposal::cast_multi_option_vote` rejects invalid choices
+    /// (wrong option index, percentages not summing to 100) at cast time, so a vote
+    /// can't reach this tally in a state that would make it fail `VoteChoice::validate`.
+    pub fn multi_option_status(
+        &self,
+        votes: &[(Balance, Vec<VoteChoice>)],
+        num_options: u8,
+        threshold: &WeightOrRatio,
+        total_weight: Balance,
+    ) -> Option<u8> {
+        let required = threshold.to_weight(total_weight);
+        let mut tallies = vec![0u128; num_options as usize];
+        for (voter_weight, choices) in votes {
+            for choice in choices {
+                tallies[choice.option_index as usize] +=
+                    voter_weight * choice.weight_percentage as u128 / 100;
+            }
+        }
+        tallies
+            .iter()
+            .position(|&tally| tally >= required)
+            .map(|idx| idx as u8)
+    }
+
     /// Get proposal status for given proposal.
     /// Usually is called after changing it's state.
-    pub fn proposal_status(&self, proposal: &Proposal, total_supply: Balance) -> ProposalStatus {
+    /// `total_quadratic_weight` is the sum of the integer square roots of every token
+    /// holder's balance, as computed by `quadratic_total_weight` over the full holder
+    /// set; it's only consulted when the resolved `VotePolicy` uses
+    /// `WeightKind::QuadraticTokenWeight`, since that weighting isn't additive from
+    /// `vote_counts` alone and must be re-tallied per voter via `quadratic_vote_weight`.
+    pub fn proposal_status(
+        &self,
+        proposal: &Proposal,
+        total_supply: Balance,
+        total_quadratic_weight: Balance,
+    ) -> ProposalStatus {
         assert_eq!(
             proposal.status,
             ProposalStatus::InProgress,
@@ -219,25 +435,562 @@ impl Policy {
             .vote_policy
             .get(&proposal.kind.to_policy_label().to_string())
             .unwrap_or(&self.default_vote_policy);
-        let threshold = match &vote_policy.weight_kind {
-            WeightKind::TokenWeight => vote_policy.threshold.to_weight(total_supply),
-            WeightKind::RoleWeight(role) => {
-                self.internal_get_role(role)
+        if let ProposalKind::MultiOption { options } = &proposal.kind {
+            let votes: Vec<(Balance, Vec<VoteChoice>)> = proposal
+                .vote_choices
+                .iter()
+                .map(|(voter, choices)| {
+                    let weight = proposal.voter_balances.get(voter).copied().unwrap_or(1);
+                    (weight, choices.clone())
+                })
+                .collect();
+            let total_weight = match &vote_policy.weight_kind {
+                WeightKind::TokenWeight | WeightKind::QuadraticTokenWeight => total_supply,
+                WeightKind::RoleWeight(role) => self
+                    .internal_get_role(role)
                     .expect("ERR_MISSING_ROLE")
                     .kind
                     .get_role_size()
-                    .expect("ERR_UNSUPPORTED_ROLE") as Balance
+                    .expect("ERR_UNSUPPORTED_ROLE") as Balance,
+            };
+            return match self.multi_option_status(
+                &votes,
+                options.len() as u8,
+                &vote_policy.threshold,
+                total_weight,
+            ) {
+                Some(winner) => ProposalStatus::ApprovedOption(winner),
+                None => proposal.status.clone(),
+            };
+        }
+        let (approve, reject, remove) = if let WeightKind::QuadraticTokenWeight =
+            vote_policy.weight_kind
+        {
+            let mut balances: [Vec<Balance>; 3] = [Vec::new(), Vec::new(), Vec::new()];
+            for (voter, vote) in proposal.votes.iter() {
+                if let Some(balance) = proposal.voter_balances.get(voter) {
+                    balances[*vote as usize].push(*balance);
+                }
+            }
+            (
+                self.quadratic_vote_weight(&balances[Vote::Approve as usize]),
+                self.quadratic_vote_weight(&balances[Vote::Reject as usize]),
+                self.quadratic_vote_weight(&balances[Vote::Remove as usize]),
+            )
+        } else {
+            (
+                proposal.vote_counts[Vote::Approve as usize],
+                proposal.vote_counts[Vote::Reject as usize],
+                proposal.vote_counts[Vote::Remove as usize],
+            )
+        };
+        let total_weight = match &vote_policy.weight_kind {
+            WeightKind::TokenWeight => total_supply,
+            WeightKind::QuadraticTokenWeight => total_quadratic_weight,
+            WeightKind::RoleWeight(role) => self
+                .internal_get_role(role)
+                .expect("ERR_MISSING_ROLE")
+                .kind
+                .get_role_size()
+                .expect("ERR_UNSUPPORTED_ROLE") as Balance,
+        };
+        let threshold = match &vote_policy.weight_kind {
+            WeightKind::TokenWeight | WeightKind::QuadraticTokenWeight => {
+                vote_policy.threshold.to_weight(total_weight)
             }
+            WeightKind::RoleWeight(_) => total_weight,
         };
+        let participation = approve + reject + remove;
+        if participation < vote_policy.quorum.to_weight(total_weight) {
+            return proposal.status.clone();
+        }
         // Check if there is anything voted above the threshold specificed by policy.
-        if proposal.vote_counts[Vote::Approve as usize] >= threshold {
+        if approve >= threshold {
+            if proposal.pending_execution {
+                ProposalStatus::ApprovedPendingExecution
+            } else {
+                ProposalStatus::Approved
+            }
+        } else if reject >= threshold {
+            ProposalStatus::Rejected
+        } else if remove >= threshold {
+            ProposalStatus::Removed
+        } else {
+            proposal.status.clone()
+        }
+    }
+
+    /// Resolves a role-weighted proposal whose voting period has elapsed without
+    /// crossing the threshold, by imputing the prime member's recorded vote to every
+    /// role member who abstained, then checking each tally against the policy's
+    /// `threshold` applied to the role size (not the full role size that
+    /// `proposal_status` requires for role-weighted proposals). If the policy
+    /// has no prime, or the prime itself abstained, the proposal is left unresolved,
+    /// since there is no default vote to impute.
+    pub fn expired_status(
+        &self,
+        proposal: &Proposal,
+        votes: &HashMap<AccountId, Vote>,
+    ) -> ProposalStatus {
+        let vote_policy = self
+            .vote_policy
+            .get(&proposal.kind.to_policy_label().to_string())
+            .unwrap_or(&self.default_vote_policy);
+        let prime = match &vote_policy.prime {
+            Some(prime) => prime,
+            None => return proposal.status.clone(),
+        };
+        let prime_vote = match votes.get(prime) {
+            Some(vote) => *vote,
+            None => return proposal.status.clone(),
+        };
+        let role = match &vote_policy.weight_kind {
+            WeightKind::RoleWeight(role) => self.internal_get_role(role).expect("ERR_MISSING_ROLE"),
+            _ => return proposal.status.clone(),
+        };
+        // `Policy::validate` is meant to catch a misconfigured prime at policy-write
+        // time, but nothing calls it yet, so keep this inline guard as the real runtime
+        // check until a write path wires `validate` in.
+        assert!(
+            role.kind.match_user(&UserInfo {
+                account_id: prime.clone(),
+                amount: None,
+            }),
+            "ERR_PRIME_NOT_MEMBER"
+        );
+        let members = role
+            .kind
+            .get_role_members()
+            .expect("ERR_UNSUPPORTED_ROLE");
+        let mut vote_counts = proposal.vote_counts;
+        for member in members {
+            if !votes.contains_key(member) {
+                vote_counts[prime_vote as usize] += 1;
+            }
+        }
+        let role_size = role.kind.get_role_size().expect("ERR_UNSUPPORTED_ROLE") as Balance;
+        let threshold = vote_policy.threshold.to_weight(role_size);
+        if vote_counts[Vote::Approve as usize] >= threshold {
             ProposalStatus::Approved
-        } else if proposal.vote_counts[Vote::Reject as usize] >= threshold {
+        } else if vote_counts[Vote::Reject as usize] >= threshold {
             ProposalStatus::Rejected
-        } else if proposal.vote_counts[Vote::Remove as usize] >= threshold {
+        } else if vote_counts[Vote::Remove as usize] >= threshold {
             ProposalStatus::Removed
         } else {
             proposal.status.clone()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acc(name: &str) -> AccountId {
+        name.parse().unwrap()
+    }
+
+    fn council_policy() -> Policy {
+        Policy {
+            roles: vec![RolePermission {
+                name: "council".to_string(),
+                kind: RoleKind::Group(vec![acc("alice.near"), acc("bob.near"), acc("carol.near")]),
+                permissions: vec!["*:*".to_string()].into_iter().collect(),
+            }],
+            default_vote_policy: VotePolicy::default(),
+            vote_policy: HashMap::default(),
+            bounty_bond: U128(10u128.pow(24)),
+            bounty_forgiveness_period: WrappedDuration::from(1_000_000_000 * 60 * 60 * 24),
+            parent_dao: None,
+            sudo: None,
+        }
+    }
+
+    fn multi_option_proposal(options: Vec<&str>) -> Proposal {
+        Proposal {
+            kind: ProposalKind::MultiOption {
+                options: options.into_iter().map(|o| o.to_string()).collect(),
+            },
+            status: ProposalStatus::InProgress,
+            vote_counts: [0, 0, 0],
+            votes: HashMap::new(),
+            voter_balances: HashMap::new(),
+            vote_choices: HashMap::new(),
+            pending_execution: false,
+            executed: false,
+        }
+    }
+
+    #[test]
+    fn test_multi_option_status_picks_winning_option() {
+        let policy = council_policy();
+        let mut proposal = multi_option_proposal(vec!["a", "b"]);
+        assert!(proposal.cast_multi_option_vote(
+            acc("alice.near"),
+            vec![VoteChoice {
+                option_index: 0,
+                weight_percentage: 100,
+            }],
+            1,
+        ));
+        assert!(proposal.cast_multi_option_vote(
+            acc("bob.near"),
+            vec![VoteChoice {
+                option_index: 0,
+                weight_percentage: 100,
+            }],
+            1,
+        ));
+        assert_eq!(
+            policy.proposal_status(&proposal, 0, 0),
+            ProposalStatus::ApprovedOption(0)
+        );
+    }
+
+    #[test]
+    fn test_multi_option_vote_rejects_bad_percentages() {
+        let mut proposal = multi_option_proposal(vec!["a", "b"]);
+        assert!(!proposal.cast_multi_option_vote(
+            acc("alice.near"),
+            vec![VoteChoice {
+                option_index: 0,
+                weight_percentage: 60,
+            }],
+            1,
+        ));
+        assert!(proposal.vote_choices.is_empty());
+    }
+
+    #[test]
+    fn test_multi_option_status_weights_by_token_balance() {
+        let mut policy = council_policy();
+        policy.default_vote_policy = VotePolicy {
+            weight_kind: WeightKind::TokenWeight,
+            threshold: WeightOrRatio::Ratio(1, 2),
+            prime: None,
+            quorum: WeightOrRatio::Weight(U128(0)),
+        };
+        let mut proposal = multi_option_proposal(vec!["a", "b"]);
+        // Whale holds 90 of the 100 token total supply and votes for option 1; nine
+        // small holders with 1 token each vote for option 0. Under a weight-1-per-voter
+        // bug, option 0 would lead 9 to 1 and option 1 could not win; weighted correctly
+        // by balance, option 1 wins.
+        assert!(proposal.cast_multi_option_vote(
+            acc("whale.near"),
+            vec![VoteChoice {
+                option_index: 1,
+                weight_percentage: 100,
+            }],
+            90,
+        ));
+        for i in 0..9 {
+            assert!(proposal.cast_multi_option_vote(
+                acc(&format!("small{}.near", i)),
+                vec![VoteChoice {
+                    option_index: 0,
+                    weight_percentage: 100,
+                }],
+                1,
+            ));
+        }
+        assert_eq!(
+            policy.proposal_status(&proposal, 100, 0),
+            ProposalStatus::ApprovedOption(1)
+        );
+    }
+
+    fn quadratic_policy() -> Policy {
+        let mut policy = council_policy();
+        policy.default_vote_policy = VotePolicy {
+            weight_kind: WeightKind::QuadraticTokenWeight,
+            threshold: WeightOrRatio::Ratio(1, 2),
+            prime: None,
+            quorum: WeightOrRatio::Weight(U128(0)),
+        };
+        policy
+    }
+
+    fn base_proposal() -> Proposal {
+        Proposal {
+            kind: ProposalKind::Transfer,
+            status: ProposalStatus::InProgress,
+            vote_counts: [0, 0, 0],
+            votes: HashMap::new(),
+            voter_balances: HashMap::new(),
+            vote_choices: HashMap::new(),
+            pending_execution: false,
+            executed: false,
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(4), 2);
+        assert_eq!(isqrt(10), 3);
+        assert_eq!(isqrt(10_000), 100);
+    }
+
+    #[test]
+    fn test_quadratic_weight_dampens_whale() {
+        let policy = quadratic_policy();
+        let mut proposal = base_proposal();
+        // A single whale with 50 tokens votes to reject...
+        proposal.cast_vote(acc("whale.near"), Vote::Reject, 50);
+        // ...while ten small holders with 1 token each vote to approve. Under plain
+        // TokenWeight the whale alone would outweigh all ten (50 > 10), but under
+        // QuadraticTokenWeight the whale only contributes isqrt(50) = 7, while the ten
+        // small holders contribute 10 * isqrt(1) = 10, so approve wins.
+        for i in 0..10 {
+            proposal.cast_vote(acc(&format!("small{}.near", i)), Vote::Approve, 1);
+        }
+        let total_quadratic_weight = isqrt(50) + 10 * isqrt(1);
+        assert_eq!(
+            policy.proposal_status(&proposal, 0, total_quadratic_weight),
+            ProposalStatus::Approved
+        );
+    }
+
+    fn prime_policy() -> Policy {
+        let mut policy = council_policy();
+        policy.default_vote_policy.prime = Some(acc("alice.near"));
+        policy
+    }
+
+    #[test]
+    fn test_prime_member_fills_abstentions() {
+        let policy = prime_policy();
+        let mut proposal = base_proposal();
+        // Prime votes Approve; bob and carol never vote before the proposal expires.
+        proposal.cast_vote(acc("alice.near"), Vote::Approve, 1);
+        let votes = proposal.votes.clone();
+        assert_eq!(
+            policy.expired_status(&proposal, &votes),
+            ProposalStatus::Approved
+        );
+    }
+
+    #[test]
+    fn test_prime_abstained_stays_unresolved() {
+        let policy = prime_policy();
+        let mut proposal = base_proposal();
+        // bob votes Approve, but the prime (alice) never voted, so there's no default
+        // vote to impute and the proposal must stay unresolved.
+        proposal.cast_vote(acc("bob.near"), Vote::Approve, 1);
+        let votes = proposal.votes.clone();
+        assert_eq!(
+            policy.expired_status(&proposal, &votes),
+            ProposalStatus::InProgress
+        );
+    }
+
+    #[test]
+    fn test_expired_status_uses_configured_threshold_not_full_role_size() {
+        let members: Vec<AccountId> = (0..10).map(|i| acc(&format!("m{}.near", i))).collect();
+        let mut policy = council_policy();
+        policy.roles = vec![RolePermission {
+            name: "council".to_string(),
+            kind: RoleKind::Group(members.clone()),
+            permissions: vec!["*:*".to_string()].into_iter().collect(),
+        }];
+        policy.default_vote_policy.prime = Some(members[0].clone());
+        let mut proposal = base_proposal();
+        // Prime (m0) explicitly approves, m9 explicitly rejects, m1..m8 abstain. Imputing
+        // the prime's vote to the 8 abstainers gives approve=9, reject=1 out of 10 — a
+        // clear majority under the default 1/2 threshold, even though 9 is still short
+        // of the full role size of 10.
+        proposal.cast_vote(members[0].clone(), Vote::Approve, 1);
+        proposal.cast_vote(members[9].clone(), Vote::Reject, 1);
+        let votes = proposal.votes.clone();
+        assert_eq!(
+            policy.expired_status(&proposal, &votes),
+            ProposalStatus::Approved
+        );
+    }
+
+    #[test]
+    fn test_sudo_can_execute_without_role_membership() {
+        let mut policy = council_policy();
+        let outsider = UserInfo {
+            account_id: acc("parent-dao.near"),
+            amount: None,
+        };
+        assert!(!policy.can_execute_action(
+            UserInfo {
+                account_id: acc("parent-dao.near"),
+                amount: None,
+            },
+            &ProposalKind::Transfer,
+            &Action::VoteApprove,
+            None,
+        ));
+        policy.sudo = Some(acc("parent-dao.near"));
+        assert!(policy.can_execute_action(
+            outsider,
+            &ProposalKind::Transfer,
+            &Action::VoteApprove,
+            None,
+        ));
+    }
+
+    #[test]
+    fn test_parent_role_is_unioned_unless_shadowed_locally() {
+        let policy = council_policy();
+        let parent_roles = vec![RolePermission {
+            name: "from_parent".to_string(),
+            kind: RoleKind::Group(vec![acc("dave.near")]),
+            permissions: vec!["transfer:vote_approve".to_string()]
+                .into_iter()
+                .collect(),
+        }];
+        // dave.near isn't in the local council, but is granted the permission via the
+        // parent-resolved role, since there's no locally defined role named "from_parent"
+        // to shadow it.
+        assert!(policy.can_execute_action(
+            UserInfo {
+                account_id: acc("dave.near"),
+                amount: None,
+            },
+            &ProposalKind::Transfer,
+            &Action::VoteApprove,
+            Some(&parent_roles),
+        ));
+        // A stranger who isn't in the parent role either gets nothing.
+        assert!(!policy.can_execute_action(
+            UserInfo {
+                account_id: acc("eve.near"),
+                amount: None,
+            },
+            &ProposalKind::Transfer,
+            &Action::VoteApprove,
+            Some(&parent_roles),
+        ));
+    }
+
+    #[test]
+    fn test_resolve_parent_roles_unions_whole_ancestor_chain() {
+        let mut policy = council_policy();
+        policy.parent_dao = Some(acc("parent.near"));
+        let mut grandparent = council_policy();
+        grandparent.roles = vec![
+            RolePermission {
+                name: "council".to_string(),
+                kind: RoleKind::Group(vec![acc("grandparent-council.near")]),
+                permissions: vec!["*:*".to_string()].into_iter().collect(),
+            },
+            RolePermission {
+                name: "from_grandparent".to_string(),
+                kind: RoleKind::Group(vec![acc("dave.near")]),
+                permissions: vec!["transfer:vote_approve".to_string()]
+                    .into_iter()
+                    .collect(),
+            },
+        ];
+        let mut parent = council_policy();
+        parent.parent_dao = Some(acc("grandparent.near"));
+        parent.roles = vec![RolePermission {
+            name: "from_parent".to_string(),
+            kind: RoleKind::Group(vec![acc("eve.near")]),
+            permissions: vec!["transfer:vote_approve".to_string()]
+                .into_iter()
+                .collect(),
+        }];
+        let resolved = policy.resolve_parent_roles(|account| {
+            if account == &acc("parent.near") {
+                Some(parent.clone())
+            } else if account == &acc("grandparent.near") {
+                Some(grandparent.clone())
+            } else {
+                None
+            }
+        });
+        let names: HashSet<&String> = resolved.iter().map(|role| &role.name).collect();
+        // Pulls in both the parent's own role and the grandparent's role that the
+        // parent didn't shadow; the grandparent's "council" is shadowed by the
+        // policy's own "council" role and so never appears in `resolved`.
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"from_parent".to_string()));
+        assert!(names.contains(&"from_grandparent".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_PARENT_DAO_CYCLE")]
+    fn test_resolve_parent_roles_detects_cycle() {
+        let mut policy = council_policy();
+        policy.parent_dao = Some(acc("dao-a.near"));
+        let mut dao_a = council_policy();
+        dao_a.parent_dao = Some(acc("dao-b.near"));
+        let mut dao_b = council_policy();
+        // dao-b's parent points back to dao-a, forming a cycle.
+        dao_b.parent_dao = Some(acc("dao-a.near"));
+        policy.resolve_parent_roles(|account| {
+            if account == &acc("dao-a.near") {
+                Some(dao_a.clone())
+            } else if account == &acc("dao-b.near") {
+                Some(dao_b.clone())
+            } else {
+                None
+            }
+        });
+    }
+
+    #[test]
+    fn test_quorum_not_met_keeps_proposal_in_progress() {
+        let mut policy = council_policy();
+        policy.default_vote_policy = VotePolicy {
+            weight_kind: WeightKind::TokenWeight,
+            // Threshold alone would pass with 60 of 100 total supply approving
+            // (60 >= 50), but quorum independently requires 80 of total supply to
+            // have participated at all, which 60 falls short of.
+            threshold: WeightOrRatio::Ratio(1, 2),
+            prime: None,
+            quorum: WeightOrRatio::Weight(U128(80)),
+        };
+        let mut proposal = base_proposal();
+        proposal.cast_vote(acc("alice.near"), Vote::Approve, 60);
+        assert_eq!(
+            policy.proposal_status(&proposal, 100, 0),
+            ProposalStatus::InProgress
+        );
+    }
+
+    fn token_weight_policy() -> Policy {
+        let mut policy = council_policy();
+        policy.default_vote_policy = VotePolicy {
+            weight_kind: WeightKind::TokenWeight,
+            threshold: WeightOrRatio::Ratio(1, 2),
+            prime: None,
+            quorum: WeightOrRatio::Weight(U128(0)),
+        };
+        policy
+    }
+
+    #[test]
+    fn test_approve_no_execute_cannot_mutate_state_until_executed() {
+        let policy = token_weight_policy();
+        let mut proposal = base_proposal();
+        proposal.cast_approve_no_execute_vote(acc("alice.near"), 100);
+        assert_eq!(
+            policy.proposal_status(&proposal, 100, 0),
+            ProposalStatus::ApprovedPendingExecution
+        );
+        proposal.status = ProposalStatus::ApprovedPendingExecution;
+        // The proposal is approved in principle, but its side effects haven't run yet:
+        // `executed` stays false until `mark_executed` is called.
+        assert!(!proposal.executed);
+        proposal.mark_executed();
+        assert!(proposal.executed);
+        assert_eq!(proposal.status, ProposalStatus::Approved);
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_NOT_APPROVED_PENDING_EXECUTION")]
+    fn test_execute_rejected_before_approved_pending_execution() {
+        let mut proposal = base_proposal();
+        proposal.cast_vote(acc("alice.near"), Vote::Approve, 1);
+        // Still InProgress: a plain approve vote never sets pending_execution, so
+        // mark_executed must refuse to run.
+        proposal.mark_executed();
+    }
+}
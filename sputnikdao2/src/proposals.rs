@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, Balance};
+
+use crate::policy::VoteChoice;
+
+/// How a single voter decided on a plain approve/reject/remove proposal.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Vote {
+    Approve = 0,
+    Reject = 1,
+    Remove = 2,
+}
+
+/// High level kind of what a proposal will execute, used to scope permissions and
+/// voting policy via `to_policy_label`.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalKind {
+    ChangePolicy,
+    AddMemberToRole,
+    RemoveMemberFromRole,
+    FunctionCall,
+    Transfer,
+    SetVoteToken,
+    /// Let voters split their weight across a fixed list of named options, e.g. budget
+    /// allocation or multi-candidate elections, instead of the usual approve/reject/remove
+    /// choice. Resolved via `Policy::multi_option_status`.
+    MultiOption { options: Vec<String> },
+}
+
+impl ProposalKind {
+    /// Returns label used for matching against role permissions and vote policies.
+    pub fn to_policy_label(&self) -> &str {
+        match self {
+            ProposalKind::ChangePolicy => "policy",
+            ProposalKind::AddMemberToRole => "add_member_to_role",
+            ProposalKind::RemoveMemberFromRole => "remove_member_from_role",
+            ProposalKind::FunctionCall => "call",
+            ProposalKind::Transfer => "transfer",
+            ProposalKind::SetVoteToken => "set_vote_token",
+            ProposalKind::MultiOption { .. } => "multi_option",
+        }
+    }
+}
+
+/// Status of a proposal.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalStatus {
+    InProgress,
+    Approved,
+    Rejected,
+    Removed,
+    /// Winner of a `ProposalKind::MultiOption` proposal, by option index.
+    ApprovedOption(u8),
+    /// Approved via an `Action::VoteApproveNoExecute` vote, but not yet executed.
+    /// A separate `Action::Execute` call is required to run its side effects and
+    /// move it to `Approved`; see `Proposal::mark_executed`.
+    ApprovedPendingExecution,
+}
+
+/// A single proposal being voted on.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub kind: ProposalKind,
+    pub status: ProposalStatus,
+    /// Scalar tallies for the plain Approve/Reject/Remove vote, indexed by `Vote as usize`.
+    pub vote_counts: [Balance; 3],
+    /// Per-voter record of which way they voted, used to impute the prime member's vote
+    /// to abstainers.
+    pub votes: HashMap<AccountId, Vote>,
+    /// Per-voter weight at the time they voted: token balance for token-weighted
+    /// proposals, 1 for role-weighted ones.
+    pub voter_balances: HashMap<AccountId, Balance>,
+    /// Per-voter option split for `ProposalKind::MultiOption` proposals.
+    pub vote_choices: HashMap<AccountId, Vec<VoteChoice>>,
+    /// Set by `cast_approve_no_execute_vote` to route an approve outcome to
+    /// `ProposalStatus::ApprovedPendingExecution` instead of `Approved`.
+    pub pending_execution: bool,
+    /// Set by `mark_executed` once an `ApprovedPendingExecution` proposal's side
+    /// effects have run, to guard against executing it twice.
+    pub executed: bool,
+}
+
+impl Proposal {
+    /// Records a plain approve/reject/remove vote, rejecting a second vote by the same
+    /// account. `weight` is the weight this vote contributes to `vote_counts` (1 for
+    /// role-weighted proposals, token balance for token-weighted ones).
+    pub fn cast_vote(&mut self, voter: AccountId, vote: Vote, weight: Balance) {
+        assert!(!self.votes.contains_key(&voter), "ERR_ALREADY_VOTED");
+        self.vote_counts[vote as usize] += weight;
+        self.voter_balances.insert(voter.clone(), weight);
+        self.votes.insert(voter, vote);
+    }
+
+    /// Records a multi-option vote, validating at cast time that the choices reference
+    /// valid options and that their percentages sum to 100. Returns `false` and leaves
+    /// the proposal unchanged if the choices are invalid, rather than panicking later
+    /// during status tallying. `weight` is recorded in `voter_balances` exactly like
+    /// `cast_vote` does, so `Policy::proposal_status` can weight each voter's choices
+    /// by it instead of treating every multi-option voter as weight 1.
+    pub fn cast_multi_option_vote(
+        &mut self,
+        voter: AccountId,
+        choices: Vec<VoteChoice>,
+        weight: Balance,
+    ) -> bool {
+        let num_options = match &self.kind {
+            ProposalKind::MultiOption { options } => options.len() as u8,
+            _ => return false,
+        };
+        if self.vote_choices.contains_key(&voter) {
+            return false;
+        }
+        if !VoteChoice::validate(&choices, num_options) {
+            return false;
+        }
+        self.voter_balances.insert(voter.clone(), weight);
+        self.vote_choices.insert(voter, choices);
+        true
+    }
+
+    /// Records an approve vote like `cast_vote(voter, Vote::Approve, weight)` and sets
+    /// `pending_execution` on the whole proposal, so any approve outcome from then on,
+    /// whichever vote crosses the threshold, resolves to
+    /// `ProposalStatus::ApprovedPendingExecution` rather than `Approved`.
+    pub fn cast_approve_no_execute_vote(&mut self, voter: AccountId, weight: Balance) {
+        self.pending_execution = true;
+        self.cast_vote(voter, Vote::Approve, weight);
+    }
+
+    /// Runs once a proposal has resolved to `ApprovedPendingExecution`, marking it
+    /// `Approved` so its side effects can be executed. Panics if the proposal isn't
+    /// in that status, or has already been executed.
+    pub fn mark_executed(&mut self) {
+        assert_eq!(
+            self.status,
+            ProposalStatus::ApprovedPendingExecution,
+            "ERR_NOT_APPROVED_PENDING_EXECUTION"
+        );
+        assert!(!self.executed, "ERR_ALREADY_EXECUTED");
+        self.executed = true;
+        self.status = ProposalStatus::Approved;
+    }
+}
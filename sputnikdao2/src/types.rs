@@ -0,0 +1,47 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Actions that can be performed on a proposal, gated by `Policy::can_execute_action`.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Action {
+    /// Propose to add.
+    AddProposal,
+    /// Remove given proposal. Used for cleaning up the storage.
+    RemoveProposal,
+    /// Vote to approve given proposal or bounty.
+    VoteApprove,
+    /// Vote to reject given proposal or bounty.
+    VoteReject,
+    /// Vote to remove given proposal or bounty (because it's spam).
+    VoteRemove,
+    /// Vote to approve given proposal without executing it, moving it to
+    /// `ProposalStatus::ApprovedPendingExecution` instead of running its side effects
+    /// immediately. Distinct from `VoteApprove` so a role can be granted one without
+    /// the other, e.g. a council that can approve but not itself trigger execution.
+    VoteApproveNoExecute,
+    /// Executes a proposal already in `ProposalStatus::ApprovedPendingExecution`.
+    Execute,
+    /// Finalize proposal, called when it's expired to return the funds
+    /// (or in case of vote to remove it, to avoid storage leak).
+    Finalize,
+    /// Move a proposal to the hub to shift into another DAO.
+    MoveToHub,
+}
+
+impl Action {
+    /// Returns label used for matching against role permissions.
+    pub fn to_policy_label(&self) -> &str {
+        match self {
+            Action::AddProposal => "add_proposal",
+            Action::RemoveProposal => "remove_proposal",
+            Action::VoteApprove => "vote_approve",
+            Action::VoteReject => "vote_reject",
+            Action::VoteRemove => "vote_remove",
+            Action::VoteApproveNoExecute => "vote_approve_no_execute",
+            Action::Execute => "execute",
+            Action::Finalize => "finalize",
+            Action::MoveToHub => "move_to_hub",
+        }
+    }
+}